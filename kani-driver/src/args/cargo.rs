@@ -40,6 +40,11 @@ pub struct CargoCommonArgs {
     /// Exclude the specified packages
     #[arg(long, short, requires("workspace"), conflicts_with("package"), num_args(1..))]
     pub exclude: Vec<String>,
+
+    /// Also verify the harnesses declared in the local path dependencies of the selected packages.
+    /// This is an unstable feature and requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true)]
+    pub include_dependency_harnesses: bool,
 }
 
 impl CargoCommonArgs {
@@ -195,6 +195,11 @@ pub struct VerificationArgs {
     /// Kani will only compile the crate. No verification will be performed
     #[arg(long, hide_short_help = true)]
     pub only_codegen: bool,
+    /// List the proof harnesses found in the crate, one per line, without verifying them.
+    /// Harness filters such as `--harness` are applied to the list.
+    /// This is an unstable feature and requires `-Z unstable-options` to be used.
+    #[arg(long, hide_short_help = true, conflicts_with = "only_codegen")]
+    pub list_harnesses: bool,
 
     /// Deprecated flag. This is a no-op since we no longer support the legacy linker and
     /// it will be removed in a future Kani release.
@@ -460,6 +465,10 @@ fn validate(&self) -> Result<(), Error> {
         check_no_cargo_opt(!self.verify_opts.cargo.exclude.is_empty(), "--exclude")?;
         check_no_cargo_opt(self.verify_opts.cargo.workspace, "--workspace")?;
         check_no_cargo_opt(self.verify_opts.cargo.manifest_path.is_some(), "--manifest-path")?;
+        check_no_cargo_opt(
+            self.verify_opts.cargo.include_dependency_harnesses,
+            "--include-dependency-harnesses",
+        )?;
         if let Some(input) = &self.input {
             if !input.is_file() {
                 return Err(Error::raw(
@@ -644,6 +653,26 @@ fn validate(&self) -> Result<(), Error> {
             ));
         }
 
+        if self.list_harnesses
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                "The `--list-harnesses` argument is unstable and requires `-Z \
+            unstable-options` to be used.",
+            ));
+        }
+
+        if self.cargo.include_dependency_harnesses
+            && !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions)
+        {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                "The `--include-dependency-harnesses` argument is unstable and requires `-Z \
+            unstable-options` to be used.",
+            ));
+        }
+
         Ok(())
     }
 }
@@ -856,6 +885,30 @@ fn check_concrete_playback_unstable() {
         check("kani file.rs --concrete-playback=print");
     }
 
+    #[test]
+    fn check_list_harnesses_unstable() {
+        expect_validation_error(
+            "kani file.rs --list-harnesses",
+            ErrorKind::MissingRequiredArgument,
+        );
+        let args = StandaloneArgs::try_parse_from(
+            "kani file.rs --list-harnesses -Z unstable-options".split_whitespace(),
+        )
+        .unwrap();
+        assert!(args.verify_opts.validate().is_ok());
+        assert!(args.verify_opts.list_harnesses);
+    }
+
+    #[test]
+    fn check_include_dependency_harnesses_unstable() {
+        let parse = |args: &str| CargoKaniArgs::try_parse_from(args.split_whitespace()).unwrap();
+        let args = parse("cargo-kani --include-dependency-harnesses");
+        assert_eq!(args.validate().unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+        let args = parse("cargo-kani --include-dependency-harnesses -Z unstable-options");
+        assert!(args.validate().is_ok());
+        assert!(args.verify_opts.cargo.include_dependency_harnesses);
+    }
+
     /// Check if parsing the given argument string results in the given error.
     fn expect_validation_error(arg: &str, err: ErrorKind) {
         let args = StandaloneArgs::try_parse_from(arg.split_whitespace()).unwrap();
@@ -929,5 +982,8 @@ fn check_invalid_args<'a, I>(args: I)
         check_invalid_args("kani input.rs --workspace".split_whitespace());
         check_invalid_args("kani input.rs --package foo".split_whitespace());
         check_invalid_args("kani input.rs --exclude bar --workspace".split_whitespace());
+        check_invalid_args(
+            "kani input.rs --include-dependency-harnesses -Z unstable-options".split_whitespace(),
+        );
     }
 }
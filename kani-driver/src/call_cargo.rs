@@ -8,7 +8,9 @@
 use crate::util;
 use anyhow::{bail, Context, Result};
 use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
-use cargo_metadata::{Message, Metadata, MetadataCommand, Package, Target};
+use cargo_metadata::{
+    DependencyKind, Message, Metadata, MetadataCommand, Package, PackageId, Target,
+};
 use kani_metadata::{ArtifactType, CompilerArtifactStub};
 use std::ffi::{OsStr, OsString};
 use std::fmt::{self, Display};
@@ -68,15 +70,19 @@ pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
             cargo_args.push("--manifest-path".into());
             cargo_args.push(path.into());
         }
+
+        // Feature selection only applies to the selected packages. Cargo rejects it for packages
+        // outside of the workspace, such as the path dependencies we may also verify.
+        let mut feature_args: Vec<OsString> = vec![];
         if self.args.cargo.all_features {
-            cargo_args.push("--all-features".into());
+            feature_args.push("--all-features".into());
         }
         if self.args.cargo.no_default_features {
-            cargo_args.push("--no-default-features".into());
+            feature_args.push("--no-default-features".into());
         }
         let features = self.args.cargo.features();
         if !features.is_empty() {
-            cargo_args.push(format!("--features={}", features.join(",")).into());
+            feature_args.push(format!("--features={}", features.join(",")).into());
         }
 
         cargo_args.append(&mut cargo_config_args());
@@ -105,12 +111,23 @@ pub fn cargo_build(&self, keep_going: bool) -> Result<CargoOutputs> {
 
         let mut found_target = false;
         let packages = packages_to_verify(&self.args, &metadata)?;
+        let dependencies = if self.args.cargo.include_dependency_harnesses {
+            path_dependencies(&metadata, &packages)
+        } else {
+            vec![]
+        };
         let mut artifacts = vec![];
         let mut failed_targets = vec![];
-        for package in packages {
+        for package in packages.iter().chain(&dependencies) {
+            let is_dependency = !packages.iter().any(|pkg| pkg.id == package.id);
             for verification_target in package_targets(&self.args, package) {
+                // Only the library of a dependency can be used by the packages being verified.
+                if is_dependency && !matches!(verification_target, VerificationTarget::Lib(_)) {
+                    continue;
+                }
                 let mut cmd = setup_cargo_command()?;
                 cmd.args(&cargo_args)
+                    .args(if is_dependency { &[][..] } else { &feature_args[..] })
                     .args(vec!["-p", &package.name])
                     .args(&verification_target.to_args())
                     .args(&pkg_args)
@@ -356,6 +373,35 @@ fn packages_to_verify<'b>(
     Ok(packages)
 }
 
+/// Collect the local path dependencies of the given packages, transitively.
+///
+/// Only normal dependencies are considered. Packages from a registry or a git repository are
+/// skipped, since their harnesses are meant to be verified by their own authors. The result
+/// does not include any of the given packages.
+fn path_dependencies<'b>(metadata: &'b Metadata, packages: &[&'b Package]) -> Vec<&'b Package> {
+    let Some(resolve) = &metadata.resolve else { return vec![] };
+    let mut visited: Vec<&PackageId> = packages.iter().map(|pkg| &pkg.id).collect();
+    let mut worklist = visited.clone();
+    let mut dependencies = vec![];
+    while let Some(id) = worklist.pop() {
+        let Some(node) = resolve.nodes.iter().find(|node| node.id == *id) else { continue };
+        for dep in &node.deps {
+            let is_normal = dep.dep_kinds.iter().any(|info| info.kind == DependencyKind::Normal);
+            if !is_normal || visited.contains(&&dep.pkg) {
+                continue;
+            }
+            visited.push(&dep.pkg);
+            let package = &metadata[&dep.pkg];
+            if package.source.is_none() {
+                dependencies.push(package);
+                worklist.push(&dep.pkg);
+            }
+        }
+    }
+    trace!(?dependencies, "path_dependencies result");
+    dependencies
+}
+
 /// Extract Kani artifact that might've been generated from a given rustc artifact.
 /// Not every rustc artifact will map to a kani artifact, hence the `Option<>`.
 ///
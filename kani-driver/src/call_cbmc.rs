@@ -3,6 +3,7 @@
 
 use anyhow::{bail, Result};
 use kani_metadata::{CbmcSolver, HarnessMetadata};
+use serde::Serialize;
 use std::ffi::OsString;
 use std::fmt::Write;
 use std::path::Path;
@@ -20,7 +21,8 @@
 /// Note: Kissat was marginally better, but it is an external solver which could be more unstable.
 static DEFAULT_SOLVER: CbmcSolver = CbmcSolver::Cadical;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum VerificationStatus {
     Success,
     Failure,
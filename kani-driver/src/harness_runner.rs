@@ -4,6 +4,7 @@
 use anyhow::{bail, Result};
 use kani_metadata::{ArtifactType, HarnessMetadata};
 use rayon::prelude::*;
+use serde::Serialize;
 use std::path::Path;
 use std::thread;
 use std::sync::mpsc;
@@ -14,9 +15,10 @@
 
 use crate::args::OutputFormat;
 use crate::call_cbmc::{VerificationResult, VerificationStatus};
+use crate::cbmc_output_parser::{CheckStatus, SourceLocation};
 use crate::project::Project;
 use crate::session::KaniSession;
-use crate::util::error;
+use crate::util::{error, warning};
 
 /// A HarnessRunner is responsible for checking all proof harnesses. The data in this structure represents
 /// "background information" that the controlling driver (e.g. cargo-kani or kani) computed.
@@ -37,6 +39,58 @@ pub(crate) struct HarnessResult<'pr> {
     pub result: VerificationResult,
 }
 
+/// Machine-readable form of a single harness's verification result.
+///
+/// This is written as `<harness>.json` in `kani-harness-result/` for every verified harness, even
+/// when the human-readable output is suppressed with `--quiet`, so other tools can consume
+/// per-property results without scraping the log. It is not written with `--output-format old`,
+/// since Kani does not parse CBMC's results in that mode.
+#[derive(Serialize)]
+struct HarnessResultJson<'a> {
+    harness: &'a str,
+    status: VerificationStatus,
+    /// The CBMC exit status, only present if CBMC failed to produce any results.
+    cbmc_exit_status: Option<i32>,
+    /// Wall-clock time spent in CBMC, in seconds.
+    runtime_secs: f64,
+    properties: Vec<PropertyResultJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct PropertyResultJson<'a> {
+    id: String,
+    description: &'a str,
+    status: CheckStatus,
+    source_location: &'a SourceLocation,
+}
+
+impl<'a> HarnessResultJson<'a> {
+    fn new(harness: &'a HarnessMetadata, result: &'a VerificationResult) -> Self {
+        let (cbmc_exit_status, properties) = match &result.results {
+            Ok(properties) => (
+                None,
+                properties
+                    .iter()
+                    .map(|prop| PropertyResultJson {
+                        id: prop.property_name(),
+                        description: &prop.description,
+                        status: prop.status,
+                        source_location: &prop.source_location,
+                    })
+                    .collect(),
+            ),
+            Err(exit_status) => (Some(*exit_status), vec![]),
+        };
+        HarnessResultJson {
+            harness: &harness.pretty_name,
+            status: result.status,
+            cbmc_exit_status,
+            runtime_secs: result.runtime.as_secs_f64(),
+            properties,
+        }
+    }
+}
+
 impl<'sess, 'pr> HarnessRunner<'sess, 'pr> {
     /// Given a [`HarnessRunner`] (to abstract over how these harnesses were generated), this runs
     /// the proof-checking process for each harness in `harnesses`.
@@ -134,27 +188,51 @@ pub(crate) fn check_harness(
         } else {
             let mut result = self.with_timer(|| self.run_cbmc(binary, harness), "run_cbmc")?;
 
+            // When output is old, CBMC prints its own results and we only have a mock result,
+            // so there is nothing to save either.
+            let save_results = self.args.output_format != OutputFormat::Old;
+            let file_name = format!("./kani-harness-result/{}", harness.pretty_name);
+            let prefix = Path::new(&file_name).parent().unwrap();
+            // Failing to save the results to disk should not fail the harness itself.
+            let can_write = save_results
+                && match std::fs::create_dir_all(prefix) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        warning(&format!("Failed to create directory {}: {}", prefix.display(), e));
+                        false
+                    }
+                };
+
             // When quiet, we don't want to print anything at all.
             // When output is old, we also don't have real results to print.
             if !self.args.common_args.quiet && self.args.output_format != OutputFormat::Old {
-                let file_name = String::from("./kani-harness-result/".to_owned() + &harness.pretty_name.clone()); 
-                let path = Path::new(&file_name);
-                let prefix = path.parent().unwrap();
-                std::fs::create_dir_all(prefix).unwrap();
-
-                let file = File::create(file_name.clone());
-
                 let output = result.render(
                     &self.args.output_format,
                     harness.attributes.should_panic,
                     self.args.coverage,
                 );
 
-                if let Err(e) = writeln!(file.unwrap(), "{}", output) {
-                    eprintln!("Failed to write to file {}: {}", file_name, e);
+                if can_write {
+                    if let Err(e) =
+                        File::create(&file_name).and_then(|mut file| writeln!(file, "{}", output))
+                    {
+                        warning(&format!("Failed to write to file {}: {}", file_name, e));
+                    }
                 }
                 println!("{}", output);
+            }
 
+            // The JSON result is meant for tools, so it is written regardless of whether the
+            // human-readable output is shown.
+            if can_write {
+                let json_file_name = format!("{file_name}.json");
+                let written =
+                    serde_json::to_string_pretty(&HarnessResultJson::new(harness, &result))
+                        .map_err(std::io::Error::from)
+                        .and_then(|json| std::fs::write(&json_file_name, json));
+                if let Err(e) = written {
+                    warning(&format!("Failed to write to file {}: {}", json_file_name, e));
+                }
             }
             self.gen_and_add_concrete_playback(harness, &mut result)?;
             Ok(result)
@@ -229,4 +307,135 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
 
         Ok(())
     }
+
+    /// Prints the given harnesses sorted by name, one per line, with the location where each
+    /// harness was declared. Locations under `crate_root` are printed relative to it.
+    pub(crate) fn print_harness_list(
+        &self,
+        harnesses: &[&HarnessMetadata],
+        crate_root: Option<&Path>,
+    ) {
+        for line in harness_list(harnesses, crate_root) {
+            println!("{line}");
+        }
+    }
+}
+
+/// Formats one line per harness, with the harnesses sorted by name.
+fn harness_list(harnesses: &[&HarnessMetadata], crate_root: Option<&Path>) -> Vec<String> {
+    let mut sorted = harnesses.to_vec();
+    sorted.sort_by(|a, b| a.pretty_name.cmp(&b.pretty_name));
+    sorted
+        .iter()
+        .map(|h| {
+            let file = Path::new(&h.original_file);
+            let file = crate_root.and_then(|root| file.strip_prefix(root).ok()).unwrap_or(file);
+            format!("{} ({}:{})", h.pretty_name, file.display(), h.original_start_line)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::FailedProperties;
+    use crate::cbmc_output_parser::{Property, PropertyId};
+    use crate::metadata::mock_proof_harness;
+    use std::time::Duration;
+
+    #[test]
+    fn check_harness_result_json() {
+        let harness = mock_proof_harness("check_one", None, None, None);
+        let result = VerificationResult::mock_success();
+        let json = serde_json::to_value(HarnessResultJson::new(&harness, &result)).unwrap();
+        assert_eq!(json["harness"], "check_one");
+        assert_eq!(json["status"], "SUCCESS");
+        assert!(json["cbmc_exit_status"].is_null());
+        assert_eq!(json["runtime_secs"], 0.0);
+        assert_eq!(json["properties"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn check_harness_result_json_properties() {
+        let harness = mock_proof_harness("check_one", None, None, None);
+        let property = Property {
+            description: "assertion failed: x < 10".to_string(),
+            property_id: PropertyId {
+                fn_name: Some("check_one".to_string()),
+                class: "assertion".to_string(),
+                id: 1,
+            },
+            source_location: SourceLocation {
+                column: Some("5".to_string()),
+                file: Some("src/lib.rs".to_string()),
+                function: Some("check_one".to_string()),
+                line: Some("12".to_string()),
+            },
+            status: CheckStatus::Failure,
+            reach: None,
+            trace: None,
+        };
+        let result = VerificationResult {
+            status: VerificationStatus::Failure,
+            failed_properties: FailedProperties::Other,
+            results: Ok(vec![property]),
+            runtime: Duration::from_millis(1500),
+            generated_concrete_test: false,
+        };
+        let json = serde_json::to_value(HarnessResultJson::new(&harness, &result)).unwrap();
+        assert_eq!(json["status"], "FAILURE");
+        assert!(json["cbmc_exit_status"].is_null());
+        assert_eq!(json["runtime_secs"], 1.5);
+        let properties = json["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0]["id"], "check_one.assertion.1");
+        assert_eq!(properties[0]["description"], "assertion failed: x < 10");
+        assert_eq!(properties[0]["status"], "FAILURE");
+        let location = &properties[0]["source_location"];
+        assert_eq!(location["file"], "src/lib.rs");
+        assert_eq!(location["function"], "check_one");
+        assert_eq!(location["line"], "12");
+        assert_eq!(location["column"], "5");
+    }
+
+    #[test]
+    fn check_harness_result_json_cbmc_error() {
+        let harness = mock_proof_harness("check_one", None, None, None);
+        let result = VerificationResult {
+            status: VerificationStatus::Failure,
+            failed_properties: FailedProperties::Other,
+            results: Err(6),
+            runtime: Duration::from_secs(2),
+            generated_concrete_test: false,
+        };
+        let json = serde_json::to_value(HarnessResultJson::new(&harness, &result)).unwrap();
+        assert_eq!(json["status"], "FAILURE");
+        assert_eq!(json["cbmc_exit_status"], 6);
+        assert_eq!(json["runtime_secs"], 2.0);
+        assert_eq!(json["properties"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn check_harness_list_is_sorted() {
+        let second = mock_proof_harness("mod_b::check", None, None, None);
+        let first = mock_proof_harness("mod_a::check", None, None, None);
+        assert_eq!(
+            harness_list(&[&second, &first], None),
+            vec!["mod_a::check (<unknown>:0)", "mod_b::check (<unknown>:0)"]
+        );
+    }
+
+    #[test]
+    fn check_harness_list_relative_location() {
+        let mut inside = mock_proof_harness("inside", None, None, None);
+        inside.original_file = "/work/krate/src/lib.rs".into();
+        inside.original_start_line = 7;
+        let mut outside = mock_proof_harness("outside", None, None, None);
+        outside.original_file = "/other/lib.rs".into();
+        outside.original_start_line = 3;
+        assert_eq!(
+            harness_list(&[&inside, &outside], Some(Path::new("/work/krate"))),
+            vec!["inside (src/lib.rs:7)", "outside (/other/lib.rs:3)"]
+        );
+    }
 }
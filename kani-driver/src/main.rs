@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 #![feature(let_chains)]
 use std::ffi::OsString;
+use std::path::Path;
 use std::process::ExitCode;
 
 use anyhow::Result;
@@ -86,7 +87,7 @@ fn cargokani_main(input_args: Vec<OsString>) -> Result<()> {
     }
 
     let project = project::cargo_project(&session, false)?;
-    if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
+    if session.args.only_codegen { Ok(()) } else { verify_project(project, session, None) }
 }
 
 /// The main function for the `kani` command.
@@ -104,16 +105,32 @@ fn standalone_main() -> Result<()> {
         print_kani_version(InvocationType::Standalone);
     }
 
-    let project = project::standalone_project(&args.input.unwrap(), args.crate_name, &session)?;
-    if session.args.only_codegen { Ok(()) } else { verify_project(project, session) }
+    let input = args.input.unwrap();
+    let project = project::standalone_project(&input, args.crate_name, &session)?;
+    if session.args.only_codegen {
+        Ok(())
+    } else {
+        verify_project(project, session, input.parent())
+    }
 }
 
 /// Run verification on the given project.
-fn verify_project(project: Project, session: KaniSession) -> Result<()> {
+///
+/// Harness locations are reported relative to `crate_root` if given, or to the workspace root
+/// for `cargo kani`.
+fn verify_project(project: Project, session: KaniSession, crate_root: Option<&Path>) -> Result<()> {
     debug!(?project, "verify_project");
     let harnesses = session.determine_targets(&project.get_all_harnesses())?;
     debug!(n = harnesses.len(), ?harnesses, "verify_project");
 
+    if session.args.list_harnesses {
+        let crate_root = crate_root.or_else(|| {
+            project.cargo_metadata.as_ref().map(|metadata| metadata.workspace_root.as_std_path())
+        });
+        session.print_harness_list(&harnesses, crate_root);
+        return Ok(());
+    }
+
     // Verification
     let runner = harness_runner::HarnessRunner { sess: &session, project: &project };
     let results = runner.check_all_harnesses(&harnesses)?;
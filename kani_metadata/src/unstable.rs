@@ -87,6 +87,8 @@ pub enum UnstableFeature {
     /// Automatically check that no invalid value is produced which is considered UB in Rust.
     /// Note that this does not include checking uninitialized value.
     ValidValueChecks,
+    /// Enable unstable command line options that are not part of a larger feature.
+    UnstableOptions,
 }
 
 impl UnstableFeature {
@@ -0,0 +1,55 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Support for constraining symbolic floating point values.
+//!
+//! An unconstrained `kani::any::<f64>()` includes NaN and infinities, which make many numerical
+//! properties fail on paths that are usually not interesting. The functions in this module can be
+//! used to exclude those values.
+use crate::{any_where, assume, Arbitrary};
+
+/// Floating point types whose symbolic values can be constrained to be finite.
+pub trait FiniteFloat: Arbitrary + Copy {
+    /// Returns `true` if this value is neither infinite nor NaN.
+    fn is_finite_value(self) -> bool;
+}
+
+macro_rules! finite_float {
+    ( $type: ty ) => {
+        impl FiniteFloat for $type {
+            #[inline(always)]
+            fn is_finite_value(self) -> bool {
+                self.is_finite()
+            }
+        }
+    };
+}
+
+finite_float!(f32);
+finite_float!(f64);
+
+/// Assume that `value` is finite, i.e., it is neither infinite nor NaN.
+///
+/// # Example:
+///
+/// ```rust
+/// let x: f64 = kani::any();
+/// kani::assume_finite(x);
+/// assert!(x == x);
+/// ```
+#[inline(always)]
+pub fn assume_finite<T: FiniteFloat>(value: T) {
+    assume(value.is_finite_value());
+}
+
+/// Creates a symbolic floating point value that is neither infinite nor NaN.
+///
+/// # Example:
+///
+/// ```rust
+/// let x: f32 = kani::any_finite();
+/// assert!(x - x == 0.0);
+/// ```
+#[inline(always)]
+pub fn any_finite<T: FiniteFloat>() -> T {
+    any_where(|value: &T| value.is_finite_value())
+}
@@ -22,9 +22,11 @@
 pub mod arbitrary;
 #[cfg(feature = "concrete_playback")]
 mod concrete_playback;
+pub mod float;
 pub mod futures;
 pub mod mem;
 pub mod slice;
+pub mod string;
 pub mod tuple;
 pub mod vec;
 
@@ -42,6 +44,7 @@
 pub fn concrete_playback_run<F: Fn()>(_: Vec<Vec<u8>>, _: F) {
     unreachable!("Concrete playback does not work during verification")
 }
+pub use float::{any_finite, assume_finite};
 pub use futures::{block_on, block_on_with_spawn, spawn, yield_now, RoundRobin};
 
 /// Creates an assumption that will be valid after this statement run. Note that the assumption
@@ -93,6 +96,38 @@ macro_rules! implies {
     };
 }
 
+/// `forall!(i in range => predicate)` is true if the `predicate` holds for every value `i` in
+/// the given `range`.
+///
+/// This expands to a loop over the range, so its bound must be concrete or symbolically bounded,
+/// and the harness needs an unwinding bound of at least the length of the range plus one.
+/// The result can be checked with `assert!()` or constrained with `kani::assume()`.
+///
+/// # Example:
+///
+/// ```rust
+/// let arr: [u8; 4] = kani::any();
+/// kani::assume(kani::forall!(i in 0..3 => arr[i] <= arr[i + 1]));
+/// assert!(arr[0] <= arr[3]);
+/// ```
+#[macro_export]
+macro_rules! forall {
+    ($var:ident in $range:expr => $predicate:expr) => {
+        ($range).into_iter().all(|$var| $predicate)
+    };
+}
+
+/// `exists!(i in range => predicate)` is true if the `predicate` holds for at least one value `i`
+/// in the given `range`.
+///
+/// The same restrictions as [`forall!`] apply.
+#[macro_export]
+macro_rules! exists {
+    ($var:ident in $range:expr => $predicate:expr) => {
+        ($range).into_iter().any(|$var| $predicate)
+    };
+}
+
 /// Creates an assertion of the specified condition and message.
 ///
 /// # Example:
@@ -201,6 +236,53 @@ pub fn any_where<T: Arbitrary, F: FnOnce(&T) -> bool>(f: F) -> T {
     result
 }
 
+/// This creates a symbolic *valid* value of type `T` in the half-open range `[lo, hi)`.
+/// You can assign the return value of this function to a variable that you want to make symbolic.
+///
+/// # Example:
+///
+/// In the snippet below, we are verifying the behavior of the function `fn_under_verification`
+/// under all possible `usize` input values between 0 (inclusive) and 8 (exclusive).
+///
+/// ```rust
+/// let index: usize = kani::any_in_range(0, 8);
+/// fn_under_verification(index);
+/// ```
+///
+/// Note: If `lo >= hi`, the range is empty and every path that follows this call is pruned,
+/// which makes the remaining checks vacuously true. For floating point types, NaN is never in
+/// the range.
+#[inline(always)]
+pub fn any_in_range<T: Arbitrary + PartialOrd>(lo: T, hi: T) -> T {
+    any_where(|x| lo <= *x && *x < hi)
+}
+
+/// This creates a range that starts at `start` and whose end is a symbolic value in the
+/// inclusive range `[start, max]`.
+/// Iterating over the returned range executes a symbolic number of iterations that is at most
+/// `max - start`.
+///
+/// # Example:
+///
+/// In the snippet below, the loop body runs at most 10 times, so the harness needs an unwinding
+/// bound of at least 11 (e.g., `#[kani::unwind(11)]`).
+///
+/// ```rust
+/// let mut count = 0;
+/// for _ in kani::bounded_range(0, 10) {
+///     count += 1;
+/// }
+/// assert!(count <= 10);
+/// ```
+///
+/// Note: If `start > max`, there is no valid end and every path that follows this call is pruned,
+/// which makes the remaining checks vacuously true.
+#[inline(always)]
+pub fn bounded_range<T: Arbitrary + PartialOrd + Copy>(start: T, max: T) -> core::ops::Range<T> {
+    let end = any_where(|x| start <= *x && *x <= max);
+    start..end
+}
+
 /// This function creates a symbolic value of type `T`. This may result in an invalid value.
 ///
 /// # Safety
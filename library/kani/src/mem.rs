@@ -75,6 +75,29 @@ pub fn assert_valid_ptr<T>(ptr: *const T) -> bool
     }
 }
 
+/// Assume that the pointer is not null and that it is aligned according to the type of its
+/// pointee.
+///
+/// This is useful to model a caller that guarantees pointer validity when verifying unsafe code.
+/// Note that this does not assume that the pointer points to allocated memory. Dereferencing a
+/// dangling pointer will still be reported as a failure.
+#[crate::unstable(
+    feature = "mem-predicates",
+    issue = 2690,
+    reason = "experimental memory predicate API"
+)]
+pub fn assume_valid_ptr<T>(ptr: *const T)
+where
+    T: ?Sized,
+    <T as Pointee>::Metadata: PtrProperties<T>,
+{
+    crate::assume(!ptr.is_null());
+
+    let (thin_ptr, metadata) = ptr.to_raw_parts();
+    let align = metadata.min_alignment(Internal);
+    crate::assume(thin_ptr as usize % align == 0);
+}
+
 mod private {
     /// Define like this to restrict usage of PtrProperties functions outside Kani.
     #[derive(Copy, Clone)]
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+use crate::{assume, vec::any_vec};
+
+/// Generates an arbitrary valid UTF-8 string whose length is at most MAX_LENGTH bytes.
+///
+/// Note: Validating UTF-8 iterates over the generated bytes, so harnesses that use this
+/// function usually need an unwind bound larger than MAX_LENGTH. Prefer
+/// [`any_ascii_string`] when non-ASCII characters are not relevant to the property.
+pub fn any_string<const MAX_LENGTH: usize>() -> String
+where
+    [(); std::mem::size_of::<[u8; MAX_LENGTH]>()]:,
+{
+    let bytes = any_vec::<u8, MAX_LENGTH>();
+    assume(std::str::from_utf8(&bytes).is_ok());
+    // SAFETY: We just assumed that the bytes are valid UTF-8.
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
+
+/// Generates an arbitrary ASCII string whose length is at most MAX_LENGTH bytes.
+///
+/// This is cheaper than [`any_string`] since every byte is constrained independently.
+pub fn any_ascii_string<const MAX_LENGTH: usize>() -> String
+where
+    [(); std::mem::size_of::<[u8; MAX_LENGTH]>()]:,
+{
+    let bytes = any_vec::<u8, MAX_LENGTH>();
+    for byte in &bytes {
+        assume(byte.is_ascii());
+    }
+    // SAFETY: ASCII strings are valid UTF-8.
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
@@ -1,6 +1,6 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
-use crate::{any, any_where, Arbitrary};
+use crate::{any, any_where, assume, Arbitrary};
 
 /// Generates an arbitrary vector whose length is at most MAX_LENGTH.
 pub fn any_vec<T, const MAX_LENGTH: usize>() -> Vec<T>
@@ -22,6 +22,21 @@ pub fn any_vec<T, const MAX_LENGTH: usize>() -> Vec<T>
     }
 }
 
+/// Generates an arbitrary vector whose length is at most MAX_LENGTH, and whose elements are all in
+/// the half-open range `[lo, hi)`.
+///
+/// Note: If `lo >= hi`, the range is empty and the only vector that can be generated is the empty
+/// one.
+pub fn any_vec_in_range<T, const MAX_LENGTH: usize>(lo: T, hi: T) -> Vec<T>
+where
+    T: Arbitrary + PartialOrd,
+    [(); std::mem::size_of::<[T; MAX_LENGTH]>()]:,
+{
+    let vec = any_vec::<T, MAX_LENGTH>();
+    assume(vec.iter().all(|elem| lo <= *elem && *elem < hi));
+    vec
+}
+
 /// Generates an arbitrary vector that is exactly EXACT_LENGTH long.
 pub fn exact_vec<T, const EXACT_LENGTH: usize>() -> Vec<T>
 where
@@ -14,20 +14,26 @@
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{
-    parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Fields, GenericParam, Generics,
-    Index,
+    parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Expr, Fields,
+    GenericParam, Generics, Index, LitStr,
 };
 
 pub fn expand_derive_arbitrary(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive_item = parse_macro_input!(item as DeriveInput);
     let item_name = &derive_item.ident;
+    let assumptions = parse_assumptions(&derive_item.attrs);
+    check_field_attributes(&derive_item.data);
 
     // Add a bound `T: Arbitrary` to every type parameter T.
     let generics = add_trait_bound(derive_item.generics);
     // Generate an expression to sum up the heap size of each field.
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let body = fn_any_body(&item_name, &derive_item.data);
+    let body = if assumptions.is_empty() {
+        fn_any_body(&item_name, &derive_item.data)
+    } else {
+        fn_any_body_with_assumptions(&item_name, &derive_item.data, &assumptions)
+    };
     let expanded = quote! {
         // The generated implementation.
         impl #impl_generics kani::Arbitrary for #item_name #ty_generics #where_clause {
@@ -49,6 +55,109 @@ fn add_trait_bound(mut generics: Generics) -> Generics {
     generics
 }
 
+/// Collect the conditions of every `#[kani(assume = "<condition>")]` attribute on the item.
+fn parse_assumptions(attrs: &[Attribute]) -> Vec<Expr> {
+    let mut assumptions = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("kani")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("assume") {
+                let condition: LitStr = meta.value()?.parse()?;
+                assumptions.push(condition.parse::<Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `kani` attribute for `#[derive(Arbitrary)]`"))
+            }
+        });
+        if let Err(error) = result {
+            abort!(error.span(), "{}", error;
+                help = "the supported form is `#[kani(assume = \"<condition>\")]`"
+            )
+        }
+    }
+    assumptions
+}
+
+/// Abort if any field has a `#[kani(...)]` attribute, since none is supported on fields and
+/// ignoring it would silently drop, e.g., an assumption the user expects to hold.
+fn check_field_attributes(data: &Data) {
+    let fields: Vec<&syn::Field> = match data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+        Data::Union(data) => data.fields.named.iter().collect(),
+    };
+    fields.into_iter().for_each(|field| reject_kani_attrs(&field.attrs));
+}
+
+fn reject_kani_attrs(attrs: &[Attribute]) {
+    if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("kani")) {
+        abort!(attr, "unsupported `kani` attribute for a field";
+            help = "`#[kani(...)]` can only be used on the item or on enum variants"
+        )
+    }
+}
+
+/// Check whether an enum variant was annotated with `#[kani(skip)]`, in which case it should never
+/// be generated.
+fn is_skipped(attrs: &[Attribute]) -> bool {
+    let mut skip = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("kani")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `kani` attribute for an enum variant"))
+            }
+        });
+        if let Err(error) = result {
+            abort!(error.span(), "{}", error;
+                help = "the supported form is `#[kani(skip)]`"
+            )
+        }
+    }
+    skip
+}
+
+/// Generate the body of the function `any()` for a struct with one or more
+/// `#[kani(assume = "<condition>")]` attributes.
+/// Each field is bound to a local variable with the field's name, so the conditions can refer
+/// to the fields directly. E.g.:
+/// ```ignore
+/// #[derive(Arbitrary)]
+/// #[kani(assume = "start <= end")]
+/// struct Range { start: u8, end: u8 }
+/// ```
+/// will generate the following body for `fn any()`:
+/// ```ignore
+/// fn any() -> Self {
+///    let start: u8 = kani::any();
+///    let end: u8 = kani::any();
+///    kani::assume(start <= end);
+///    Range { start, end }
+/// }
+/// ```
+fn fn_any_body_with_assumptions(ident: &Ident, data: &Data, assumptions: &[Expr]) -> TokenStream {
+    let Data::Struct(syn::DataStruct { fields: Fields::Named(fields), .. }) = data else {
+        abort!(Span::call_site(), "Cannot derive `Arbitrary` with assumptions for `{}`", ident;
+            note = ident.span() =>
+            "`#[kani(assume = ...)]` is only supported for structs with named fields"
+        )
+    };
+    let names: Vec<_> = fields.named.iter().map(|field| &field.ident).collect();
+    let init = fields.named.iter().map(|field| {
+        let name = &field.ident;
+        let ty = &field.ty;
+        quote_spanned! {field.span()=>
+            let #name: #ty = kani::any();
+        }
+    });
+    quote! {
+        #(#init)*
+        #(kani::assume(#assumptions);)*
+        #ident { #(#names,)* }
+    }
+}
+
 /// Generate the body of the function `any()`.
 /// This will create the non-deterministic object.
 /// E.g.:
@@ -117,7 +226,10 @@ fn init_symbolic_item(ident: &Ident, fields: &Fields) -> TokenStream {
 
 /// Generate the body of the function `any()` for enums. The cases are:
 /// 1. For zero-variants enumerations, this will encode a `panic!()` statement.
-/// 2. For one or more variants, the code will be something like:
+/// 2. If only one variant is left after removing the ones annotated with `#[kani(skip)]`, the
+///    body simply creates that variant, since there is no discriminant to pick.
+/// 3. For two or more variants, the code will be something like the following, where variants
+///    annotated with `#[kani(skip)]` are left out:
 /// ```
 /// # enum Enum{
 /// #    WithoutData,
@@ -144,9 +256,23 @@ fn fn_any_enum(ident: &Ident, data: &DataEnum) -> TokenStream {
             panic!(#msg)
         }
     } else {
-        let arms = data.variants.iter().enumerate().map(|(idx, variant)| {
+        let variants: Vec<_> =
+            data.variants.iter().filter(|variant| !is_skipped(&variant.attrs)).collect();
+        if variants.is_empty() {
+            abort!(Span::call_site(), "Cannot derive `Arbitrary` for `{}` enum", ident;
+                note = ident.span() =>
+                "every variant of `{}` is annotated with `#[kani(skip)]`", ident
+            )
+        }
+        if let [variant] = variants.as_slice() {
+            let init = init_symbolic_item(&variant.ident, &variant.fields);
+            return quote! {
+                #ident::#init
+            };
+        }
+        let arms = variants.iter().enumerate().map(|(idx, variant)| {
             let init = init_symbolic_item(&variant.ident, &variant.fields);
-            if idx + 1 < data.variants.len() {
+            if idx + 1 < variants.len() {
                 let index = Index::from(idx);
                 quote! {
                     #index => #ident::#init,
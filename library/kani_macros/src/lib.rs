@@ -23,6 +23,14 @@
 
 /// Marks a Kani proof harness
 ///
+/// Harnesses may take arguments, in which case every argument is initialized with
+/// [`kani::any()`](https://model-checking.github.io/kani/crates/doc/kani/fn.any.html), i.e., the
+/// harness is checked for all possible values of its arguments. The type of each argument must
+/// implement `kani::Arbitrary`.
+///
+/// Harnesses may also return a `Result`, in which case Kani reports a verification failure if the
+/// harness can return `Err`.
+///
 /// For async harnesses, this will call [`block_on`](https://model-checking.github.io/kani/crates/doc/kani/futures/fn.block_on.html) to drive the future to completion (see its documentation for more information).
 ///
 /// If you want to spawn tasks in an async harness, you have to pass a schedule to the `#[kani::proof]` attribute,
@@ -101,8 +109,14 @@ pub fn unstable(attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 /// Allow users to auto generate Arbitrary implementations by using `#[derive(Arbitrary)]` macro.
+///
+/// For structs with named fields, the generated values can be constrained with one or more
+/// `#[kani(assume = "<condition>")]` attributes, where the condition can refer to the fields by
+/// name, e.g., `#[kani(assume = "start <= end")]`.
+///
+/// For enums, variants annotated with `#[kani(skip)]` are never generated.
 #[proc_macro_error]
-#[proc_macro_derive(Arbitrary)]
+#[proc_macro_derive(Arbitrary, attributes(kani))]
 pub fn derive_arbitrary(item: TokenStream) -> TokenStream {
     derive::expand_derive_arbitrary(item)
 }
@@ -211,9 +225,10 @@ mod sysroot {
     use super::*;
 
     use {
-        quote::{format_ident, quote},
+        quote::{format_ident, quote, quote_spanned},
         syn::parse::{Parse, ParseStream},
-        syn::{parse_macro_input, ItemFn},
+        syn::spanned::Spanned,
+        syn::{parse_macro_input, ItemFn, ReturnType},
     };
 
     /// Annotate the harness with a #[kanitool::<name>] with optional arguments.
@@ -265,6 +280,25 @@ fn parse(input: ParseStream) -> syn::Result<Self> {
         }
     }
 
+    /// Whether the function returns a `Result`. Since macros cannot resolve types, this matches
+    /// any type whose last path segment is `Result`, such as `std::io::Result<()>`.
+    fn returns_result(output: &ReturnType) -> bool {
+        match output {
+            ReturnType::Type(_, ty) => match ty.as_ref() {
+                syn::Type::Path(syn::TypePath { path, .. }) => {
+                    path.segments.last().is_some_and(|segment| segment.ident == "Result")
+                }
+                _ => false,
+            },
+            ReturnType::Default => false,
+        }
+    }
+
+    /// Wraps the call to the original harness so that returning `Err` is reported as a failure.
+    fn check_result(call: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        quote!(kani::assert(#call.is_ok(), "proof harness returned `Err`"))
+    }
+
     pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         let proof_options = parse_macro_input!(attr as ProofOptions);
         let fn_item = parse_macro_input!(item as ItemFn);
@@ -277,6 +311,7 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
             #[allow(dead_code)]
             #[kanitool::proof]
         );
+        let returns_result = returns_result(&sig.output);
 
         if sig.asyncness.is_none() {
             if proof_options.schedule.is_some() {
@@ -285,13 +320,70 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
                     help = "did you mean to make this function `async`?";
                 );
             }
-            // Adds `#[kanitool::proof]` and other attributes
-            quote!(
-                #kani_attributes
-                #(#attrs)*
-                #vis #sig #body
-            )
-            .into()
+            if sig.inputs.is_empty() && !returns_result {
+                // Adds `#[kanitool::proof]` and other attributes
+                quote!(
+                    #kani_attributes
+                    #(#attrs)*
+                    #vis #sig #body
+                )
+                .into()
+            } else {
+                // For functions with arguments, each argument is treated as universally
+                // quantified, i.e., it is initialized with `kani::any()`. Specifically, it
+                // translates
+                // ```ignore
+                // #[kani::proof]
+                // #[attribute]
+                // pub fn harness(x: u8, y: bool) { ... }
+                // ```
+                // to
+                // ```ignore
+                // #[kanitool::proof]
+                // #[attribute]
+                // pub fn harness() {
+                //   fn harness(x: u8, y: bool) { ... }
+                //   harness(kani::any::<u8>(), kani::any::<bool>())
+                // }
+                // ```
+                // Harnesses that return a `Result` are translated the same way, except that the
+                // outer function returns `()` and asserts that the result is `Ok`, i.e.:
+                // ```ignore
+                // pub fn harness() {
+                //   fn harness(x: u8) -> Result<(), E> { ... }
+                //   kani::assert(harness(kani::any::<u8>()).is_ok(), "proof harness returned `Err`")
+                // }
+                // ```
+                let args = sig.inputs.iter().map(|input| match input {
+                    syn::FnArg::Typed(syn::PatType { ty, .. }) => {
+                        // Use the type's span so a missing `Arbitrary` implementation is
+                        // reported on the argument itself.
+                        quote_spanned!(ty.span()=> kani::any::<#ty>())
+                    }
+                    syn::FnArg::Receiver(receiver) => {
+                        abort!(receiver, "`#[kani::proof]` cannot be applied to methods";
+                            help = "try moving the harness to a free function";
+                        );
+                    }
+                });
+                let mut modified_sig = sig.clone();
+                modified_sig.inputs.clear();
+                let fn_name = &sig.ident;
+                let mut call = quote!(#fn_name(#(#args),*));
+                if returns_result {
+                    modified_sig.output = ReturnType::Default;
+                    call = check_result(call);
+                }
+                quote!(
+                    #kani_attributes
+                    #(#attrs)*
+                    #vis #modified_sig {
+                        #sig #body
+                        #call
+                    }
+                )
+                .into()
+            }
         } else {
             // For async functions, it translates to a synchronous function that calls `kani::block_on`.
             // Specifically, it translates
@@ -323,11 +415,15 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
             modified_sig.asyncness = None;
             let fn_name = &sig.ident;
             let schedule = proof_options.schedule;
-            let block_on_call = if let Some(schedule) = schedule {
+            let mut block_on_call = if let Some(schedule) = schedule {
                 quote!(kani::block_on_with_spawn(#fn_name(), #schedule))
             } else {
                 quote!(kani::block_on(#fn_name()))
             };
+            if returns_result {
+                modified_sig.output = ReturnType::Default;
+                block_on_call = check_result(block_on_call);
+            }
             quote!(
                 #kani_attributes
                 #(#attrs)*
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `--include-dependency-harnesses` can be combined with `--features`. The features
+//! only apply to this package, since cargo rejects them for packages outside of the workspace.
+
+pub fn widen_sum(a: u8, b: u8) -> u16 {
+    support::widen(a) + support::widen(b)
+}
+
+#[cfg(all(kani, feature = "extra"))]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    fn check_widen_sum() {
+        let (a, b) = (kani::any(), kani::any());
+        assert!(widen_sum(a, b) <= 510);
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `--include-dependency-harnesses` also verifies the harnesses declared in a local
+//! path dependency, and not only the ones in the package being verified.
+
+pub fn sum(a: u8, b: u8) -> u16 {
+    support::widen(a) + support::widen(b)
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    fn check_sum() {
+        let (a, b) = (kani::any(), kani::any());
+        assert!(sum(a, b) <= 510);
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A support crate with its own harness, which is only verified when requested.
+
+pub fn widen(x: u8) -> u16 {
+    x as u16
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    fn check_widen() {
+        let x: u8 = kani::any();
+        assert_eq!(widen(x) as u8, x);
+    }
+}
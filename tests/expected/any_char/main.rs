@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any::<char>()` only generates valid Unicode scalar values, and that both
+//! ASCII and values beyond the surrogate range are reachable.
+
+#[kani::proof]
+fn check_char_range() {
+    let c: char = kani::any();
+    let val = c as u32;
+    assert!(!(0xD800..=0xDFFF).contains(&val));
+    assert!(val <= 0x10FFFF);
+    assert_eq!(char::from_u32(val), Some(c));
+    kani::cover!(c.is_ascii());
+    kani::cover!(val == 0xE000);
+    kani::cover!(c == char::MAX);
+}
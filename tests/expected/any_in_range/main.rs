@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::any_in_range` respects its bounds, and that the lower bound and the last
+//! value before the upper bound are both reachable.
+
+#[kani::proof]
+fn check_int_range() {
+    let x: i32 = kani::any_in_range(-5, 10);
+    assert!(x >= -5 && x < 10);
+    kani::cover!(x == -5);
+    kani::cover!(x == 9);
+}
+
+#[kani::proof]
+fn check_float_range() {
+    let x: f64 = kani::any_in_range(0.0, 1.0);
+    assert!(!x.is_nan());
+    assert!(x >= 0.0 && x < 1.0);
+    kani::cover!(x == 0.0);
+}
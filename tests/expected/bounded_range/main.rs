@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that a loop over `kani::bounded_range` runs a symbolic number of iterations that is
+//! within the given bound, and that both the shortest and the longest loops are reachable.
+
+const MAX: u32 = 5;
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_bounded_accumulation() {
+    let mut sum = 0u32;
+    let mut iterations = 0u32;
+    for i in kani::bounded_range(0, MAX) {
+        sum += i;
+        iterations += 1;
+    }
+    assert!(iterations <= MAX);
+    assert!(sum <= MAX * (MAX - 1) / 2);
+    kani::cover!(iterations == 0);
+    kani::cover!(iterations == MAX);
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that harnesses can return `Result`, and that Kani reports a failure if the harness can
+//! return `Err`.
+
+#[derive(Debug)]
+struct ParseError;
+
+fn parse_digit(byte: u8) -> Result<u8, ParseError> {
+    if byte.is_ascii_digit() { Ok(byte - b'0') } else { Err(ParseError) }
+}
+
+#[kani::proof]
+fn check_err_is_failure() -> Result<(), ParseError> {
+    let digit = parse_digit(kani::any())?;
+    assert!(digit < 10);
+    Ok(())
+}
+
+#[kani::proof]
+fn check_ok_is_success(byte: u8) -> Result<(), ParseError> {
+    kani::assume(byte.is_ascii_digit());
+    let digit = parse_digit(byte)?;
+    assert!(digit < 10);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that arbitrary `Duration` values are valid and that their arithmetic behaves as
+//! expected, including on overflow.
+
+use std::time::Duration;
+
+#[kani::proof]
+fn check_saturating_add_is_monotonic() {
+    let a: Duration = kani::any();
+    let b: Duration = kani::any();
+    let sum = a.saturating_add(b);
+    assert!(sum >= a);
+    assert!(sum >= b);
+    assert!(sum.subsec_nanos() < 1_000_000_000);
+}
+
+#[kani::proof]
+fn check_checked_add_overflow() {
+    let a: Duration = kani::any();
+    let b: Duration = kani::any();
+    match a.checked_add(b) {
+        Some(sum) => assert_eq!(sum.saturating_sub(b), a),
+        None => assert_eq!(a.saturating_add(b), Duration::MAX),
+    }
+}
+
+#[kani::proof]
+fn check_bounded_duration() {
+    let timeout: Duration = kani::any_where(|d: &Duration| *d <= Duration::from_secs(60));
+    let backoff = timeout.saturating_mul(2);
+    assert!(backoff <= Duration::from_secs(120));
+}
@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that Kani can generate arbitrary tuples, including the unit type, and that each
+//! component of a tuple can be constrained independently.
+
+#[kani::proof]
+fn check_any_unit() {
+    let unit: () = kani::any();
+    assert_eq!(unit, ());
+}
+
+#[kani::proof]
+fn check_any_triple() {
+    let (small, flag, value): (u8, bool, i32) = kani::any();
+    kani::assume(small < 10);
+    kani::assume(flag);
+    kani::assume(value < 0);
+    assert!(small < 10 && flag && value < 0);
+    kani::cover!(small == 9);
+    kani::cover!(value == i32::MIN);
+}
+
+#[kani::proof]
+fn check_any_twelve() {
+    let tuple: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, bool) = kani::any();
+    kani::assume(tuple.0 == tuple.10);
+    assert_eq!(tuple.0, tuple.10);
+}
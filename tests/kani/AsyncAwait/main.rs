@@ -28,6 +28,13 @@ pub async fn test_async_proof_harness_pub() {
     assert_eq!(async_block_result, async_fn_result);
 }
 
+#[kani::proof]
+async fn test_async_proof_harness_result() -> Result<(), String> {
+    let async_block_result = async { 42 }.await;
+    let async_fn_result = async_fn().await;
+    if async_block_result == async_fn_result { Ok(()) } else { Err("mismatch".to_string()) }
+}
+
 #[kani::proof]
 fn test_async_await() {
     // Test using the `block_on` implementation in Kani's library
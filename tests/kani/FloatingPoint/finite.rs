@@ -0,0 +1,29 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::assume_finite` and `kani::any_finite` exclude NaN and infinities.
+
+#[kani::proof]
+fn check_assume_finite_f64() {
+    let x: f64 = kani::any();
+    kani::assume_finite(x);
+    assert!(x == x);
+    assert!(x.is_finite());
+}
+
+#[kani::proof]
+fn check_assume_finite_f32() {
+    let x: f32 = kani::any();
+    kani::assume_finite(x);
+    assert!(x == x);
+    assert!(x.is_finite());
+}
+
+#[kani::proof]
+fn check_any_finite() {
+    let x: f64 = kani::any_finite();
+    let y: f32 = kani::any_finite();
+    assert!(!x.is_nan() && !x.is_infinite());
+    assert!(!y.is_nan() && !y.is_infinite());
+    assert!(x - x == 0.0);
+}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --list-harnesses
+
+//! Checks that `--list-harnesses` does not verify any harness. Both harnesses below fail, so
+//! this test would fail if they were verified.
+
+#[kani::proof]
+fn check_fail() {
+    assert!(false, "this harness should not be verified");
+}
+
+#[kani::proof]
+fn check_unreachable() {
+    unreachable!("this harness should not be verified");
+}
@@ -0,0 +1,68 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z mem-predicates
+//! Check that `kani::mem::assume_valid_ptr` constrains pointers to be non-null and aligned.
+//! Except for `check_deref_after_assume`, the pointers below are built from arbitrary addresses,
+//! so the assertions only hold because of the assumption.
+#![feature(ptr_metadata)]
+
+extern crate kani;
+
+use kani::mem::assume_valid_ptr;
+use std::mem::align_of;
+use std::ptr;
+
+trait Shape {}
+
+impl Shape for u64 {}
+
+/// Read a value through a pointer that the caller guarantees to be valid.
+unsafe fn read_u32(ptr: *const u32) -> u32 {
+    *ptr
+}
+
+/// The assumption should not affect the result when the pointer is already known to be valid.
+#[kani::proof]
+pub fn check_deref_after_assume() {
+    let values: [u32; 4] = kani::any();
+    let idx: usize = kani::any_where(|idx| *idx < values.len());
+    let ptr = &values[idx] as *const u32;
+    assume_valid_ptr(ptr);
+    assert_eq!(unsafe { read_u32(ptr) }, values[idx]);
+}
+
+#[kani::proof]
+pub fn check_arbitrary_address() {
+    let ptr = kani::any::<usize>() as *const u64;
+    assume_valid_ptr(ptr);
+    assert!(!ptr.is_null());
+    assert!(ptr.is_aligned());
+}
+
+#[kani::proof]
+pub fn check_slice_from_arbitrary_address() {
+    let addr: usize = kani::any();
+    let ptr: *const [u16] = ptr::from_raw_parts(addr as *const (), kani::any::<usize>());
+    assume_valid_ptr(ptr);
+    assert_ne!(addr, 0);
+    assert_eq!(addr % align_of::<u16>(), 0);
+}
+
+#[kani::proof]
+pub fn check_str_from_arbitrary_address() {
+    let addr: usize = kani::any();
+    let ptr: *const str = ptr::from_raw_parts(addr as *const (), kani::any::<usize>());
+    assume_valid_ptr(ptr);
+    assert_ne!(addr, 0);
+}
+
+/// The alignment of a trait object is only known through its vtable.
+#[kani::proof]
+pub fn check_dyn_alignment_from_metadata() {
+    let addr: usize = kani::any();
+    let vtable = ptr::metadata(&0u64 as &dyn Shape);
+    let ptr: *const dyn Shape = ptr::from_raw_parts(addr as *const (), vtable);
+    assume_valid_ptr(ptr);
+    assert_ne!(addr, 0);
+    assert_eq!(addr % align_of::<u64>(), 0);
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Check that harnesses with arguments are verified for all values of their arguments.
+
+#[derive(kani::Arbitrary)]
+struct Point {
+    x: i8,
+    y: i8,
+}
+
+#[kani::proof]
+fn check_single_argument(x: u8) {
+    assert!(x.checked_add(1).is_some() || x == u8::MAX);
+}
+
+#[kani::proof]
+fn check_multiple_arguments(a: u16, b: u16) {
+    assert_eq!(a as u32 + b as u32, b as u32 + a as u32);
+}
+
+#[kani::proof]
+fn check_pattern_argument((a, b): (bool, bool), Point { x, y }: Point) {
+    assert_eq!(a && b, b && a);
+    assert_eq!(x as i16 * y as i16, y as i16 * x as i16);
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_array_argument(arr: [u8; 3]) {
+    let max = arr.iter().max().unwrap();
+    assert!(arr.iter().all(|e| e <= max));
+}
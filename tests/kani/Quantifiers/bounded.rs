@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the bounded quantifier macros `kani::forall!` and `kani::exists!` can be used to
+//! both assume and assert properties over a range.
+
+const LEN: usize = 5;
+
+fn is_sorted(arr: &[u8; LEN]) -> bool {
+    kani::forall!(i in 0..LEN - 1 => arr[i] <= arr[i + 1])
+}
+
+#[kani::proof]
+#[kani::unwind(21)]
+fn check_sorted_after_sort() {
+    let mut arr: [u8; LEN] = kani::any();
+    arr.sort();
+    assert!(is_sorted(&arr));
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_sorted_bounds() {
+    let arr: [u8; LEN] = kani::any();
+    kani::assume(is_sorted(&arr));
+    assert!(kani::forall!(i in 0..LEN => arr[0] <= arr[i] && arr[i] <= arr[LEN - 1]));
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn check_exists_max() {
+    let arr: [u8; LEN] = kani::any();
+    let max = *arr.iter().max().unwrap();
+    assert!(kani::exists!(i in 0..LEN => arr[i] == max));
+    assert!(!kani::exists!(i in 0..LEN => arr[i] > max));
+}
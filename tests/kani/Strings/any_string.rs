@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Check that bounded symbolic strings respect their length bound and contents constraints.
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_any_ascii_string() {
+    let s = kani::string::any_ascii_string::<4>();
+    assert!(s.len() <= 4);
+    assert!(s.is_ascii());
+    assert_eq!(s.chars().count(), s.len());
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_any_string() {
+    let s = kani::string::any_string::<4>();
+    assert!(s.len() <= 4);
+    assert!(s.chars().count() <= s.len());
+    if let Some(c) = s.chars().next() {
+        assert!(s.starts_with(c));
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that `kani::vec::any_vec_in_range` bounds both the length and the elements of the
+//! generated vector.
+
+const MAX_LENGTH: usize = 4;
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_sum_is_bounded() {
+    let v: Vec<u8> = kani::vec::any_vec_in_range::<u8, MAX_LENGTH>(10, 20);
+    assert!(v.len() <= MAX_LENGTH);
+    let sum: u32 = v.iter().map(|elem| *elem as u32).sum();
+    assert!(sum <= 19 * MAX_LENGTH as u32);
+    assert!(sum >= 10 * v.len() as u32);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_empty_range() {
+    let v: Vec<i32> = kani::vec::any_vec_in_range::<i32, MAX_LENGTH>(5, 5);
+    assert!(v.is_empty());
+}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that Kani reports an error if every variant of an enum is annotated with
+//! `#[kani(skip)]`.
+
+#[derive(kani::Arbitrary)]
+enum Skipped {
+    #[kani(skip)]
+    First,
+    #[kani(skip)]
+    Second(u8),
+}
+
+#[kani::proof]
+fn dead_harness() {
+    panic!("This shouldn't compile");
+}
@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that variants annotated with `#[kani(skip)]` are never generated by a derived Arbitrary
+//! implementation, and that all the other variants are still reachable.
+
+extern crate kani;
+use kani::cover;
+
+#[derive(kani::Arbitrary)]
+enum Message {
+    #[kani(skip)]
+    Invalid(u8),
+    Request {
+        id: u8,
+    },
+    #[kani(skip)]
+    Corrupted,
+    Response(bool),
+}
+
+#[kani::proof]
+fn check_skipped_variants() {
+    let message: Message = kani::any();
+    assert!(!matches!(message, Message::Invalid(_) | Message::Corrupted));
+    cover!(matches!(message, Message::Request { .. }));
+    cover!(matches!(message, Message::Response(_)));
+}
@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that a derived Arbitrary implementation compiles and works when `#[kani(skip)]` leaves
+//! a single variant.
+
+extern crate kani;
+
+#[derive(kani::Arbitrary)]
+enum Reading {
+    #[kani(skip)]
+    Missing,
+    Value(u8),
+    #[kani(skip)]
+    Invalid { code: u8 },
+}
+
+#[kani::proof]
+fn check_single_variant() {
+    let reading: Reading = kani::any();
+    assert!(matches!(reading, Reading::Value(_)));
+    kani::cover!(matches!(reading, Reading::Value(42)));
+}
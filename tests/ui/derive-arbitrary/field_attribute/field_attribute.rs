@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that Kani reports an error if `#[kani(...)]` is used on a field, instead of silently
+//! ignoring it.
+
+#[derive(kani::Arbitrary)]
+struct Point {
+    #[kani(assume = "x > 200")]
+    x: u8,
+    y: u8,
+}
+
+#[kani::proof]
+fn dead_harness() {
+    panic!("This shouldn't compile");
+}
@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that Kani can automatically derive Arbitrary for generic tuple structs, and that
+//! existing `where` clauses are preserved in the derived implementation.
+
+extern crate kani;
+
+use kani::cover;
+
+#[derive(Clone, kani::Arbitrary)]
+struct Wrapper<T>(T);
+
+#[derive(kani::Arbitrary)]
+struct Cloneable<T>
+where
+    T: Clone,
+{
+    inner: T,
+}
+
+#[kani::proof]
+fn check_arbitrary_wrapper() {
+    let wrapper: Wrapper<u8> = kani::any();
+    cover!(wrapper.0 == 0);
+    cover!(wrapper.0 == u8::MAX);
+}
+
+#[kani::proof]
+fn check_arbitrary_where_clause() {
+    let cloneable: Cloneable<Wrapper<bool>> = kani::any();
+    cover!(cloneable.inner.0);
+    cover!(!cloneable.inner.0);
+}
@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that Kani reports an error if `#[kani(assume = "...")]` is used on a type other than a
+//! struct with named fields.
+
+#[derive(kani::Arbitrary)]
+#[kani(assume = "self.0 <= self.1")]
+struct Pair(u8, u8);
+
+#[kani::proof]
+fn dead_harness() {
+    panic!("This shouldn't compile");
+}
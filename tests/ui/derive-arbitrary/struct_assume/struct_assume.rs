@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that the values generated by a derived Arbitrary implementation satisfy the conditions
+//! given with `#[kani(assume = "...")]`.
+
+#[derive(kani::Arbitrary)]
+#[kani(assume = "start <= end")]
+#[kani(assume = "end - start < 10")]
+struct Range {
+    start: u8,
+    end: u8,
+}
+
+#[kani::proof]
+fn check_arbitrary_range() {
+    let range: Range = kani::any();
+    assert!(range.start <= range.end);
+    assert!(range.end - range.start < 10);
+    kani::cover!(range.start == range.end);
+    kani::cover!(range.end - range.start == 9);
+}
@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// This test checks that Kani reports a clear error when a harness argument type does not
+// implement `kani::Arbitrary`, and when the proof attribute is applied to a method.
+
+struct NotArbitrary;
+
+#[kani::proof]
+fn harness_with_non_arbitrary_arg(_arg: NotArbitrary) {}
+
+struct S;
+
+impl S {
+    #[kani::proof]
+    fn method_harness(&self) {}
+}
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --list-harnesses --harness a::check --harness c::check
+
+//! Checks that `--list-harnesses` only lists the harnesses selected by `--harness`.
+//! `b::check` would be printed between the other two if the filter was ignored.
+
+mod a {
+    #[kani::proof]
+    fn check() {}
+}
+
+mod b {
+    #[kani::proof]
+    fn check() {}
+}
+
+mod c {
+    #[kani::proof]
+    fn check() {}
+}
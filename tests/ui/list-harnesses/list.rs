@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Z unstable-options --list-harnesses
+
+//! Checks that `--list-harnesses` prints every harness sorted by name without verifying them.
+
+mod second {
+    #[kani::proof]
+    fn check() {
+        assert!(false, "this harness should not be verified");
+    }
+}
+
+mod first {
+    #[kani::proof]
+    fn check() {
+        assert!(false, "this harness should not be verified");
+    }
+}